@@ -1,9 +1,10 @@
-use clap::{self, Parser};
+use clap::{self, Parser, ValueEnum};
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 use codespan_reporting::files::SimpleFile;
 use codespan_reporting::term;
 use codespan_reporting::term::termcolor::{ColorChoice, StandardStream};
-use std::{fs, ops::Range, path::Path, process};
+use std::io::{self, Read, Write};
+use std::{fs, ops::Range, process};
 use unicode_width::UnicodeWidthStr;
 
 const SECOND: u32 = 1000;
@@ -15,39 +16,195 @@ const ARROW_SEPARATOR: &str = " --> ";
 #[derive(Parser)]
 #[command(about)]
 struct Arguments {
-    /// The SRT file to adjust
-    file: String,
-    /// The change in time
-    adjustment: String,
-    /// The output file (default: same as input file)
+    /// The SRT or WebVTT file to adjust (`-` or omitted reads from stdin)
+    file: Option<String>,
+    /// The change in time (not needed when `--anchor` is used)
+    adjustment: Option<String>,
+    /// The output file (default: same as input file; `-` writes to stdout)
     #[arg(short, long)]
     output: Option<String>,
+    /// Multiply every timestamp by this ratio, to correct framerate/drift mismatches
+    #[arg(long)]
+    scale: Option<f64>,
+    /// The pivot point for `--scale` (default: the first subtitle's start time)
+    #[arg(long)]
+    pivot: Option<String>,
+    /// A known-correct correspondence `NUMBER=TIME` or `TIME=TIME` (original
+    /// subtitle number or current time, mapped to the corrected time); give
+    /// exactly two to derive the shift and scale automatically instead of
+    /// passing them by hand
+    #[arg(long = "anchor", value_name = "NUMBER=TIME|TIME=TIME")]
+    anchors: Vec<String>,
+    /// Only adjust subtitles numbered N or later
+    #[arg(long)]
+    from: Option<u32>,
+    /// Only adjust subtitles numbered M or earlier
+    #[arg(long)]
+    to: Option<u32>,
+    /// Only adjust subtitles starting at or after this time
+    #[arg(long)]
+    after: Option<String>,
+    /// The input subtitle format (default: auto-detected from the input file's
+    /// extension or `WEBVTT` header)
+    #[arg(long, value_enum, default_value = "auto")]
+    input_format: Format,
+    /// The output subtitle format (default: auto-detected from the output file's
+    /// extension, falling back to the input format)
+    #[arg(long, value_enum, default_value = "auto")]
+    format: Format,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Srt,
+    Vtt,
+    Auto,
+}
+
+/// A subtitle file format that can be read and written as a list of `Subtitle`s.
+trait SubtitleFormat {
+    fn parse(text: &str) -> Result<Vec<Subtitle>, ParseError>;
+    fn print(subtitles: &[Subtitle]) -> String;
+}
+
+struct Srt;
+
+impl SubtitleFormat for Srt {
+    fn parse(text: &str) -> Result<Vec<Subtitle>, ParseError> {
+        parse_srt(text)
+    }
+
+    fn print(subtitles: &[Subtitle]) -> String {
+        print_subtitles(subtitles)
+    }
+}
+
+struct Vtt;
+
+impl SubtitleFormat for Vtt {
+    fn parse(text: &str) -> Result<Vec<Subtitle>, ParseError> {
+        parse_vtt(text)
+    }
+
+    fn print(subtitles: &[Subtitle]) -> String {
+        print_vtt(subtitles)
+    }
+}
+
+/// Guesses a format from a file name's extension, e.g. for `--format auto`.
+fn format_from_extension(name: &str) -> Option<Format> {
+    let lower = name.to_ascii_lowercase();
+    if lower.ends_with(".vtt") {
+        Some(Format::Vtt)
+    } else if lower.ends_with(".srt") {
+        Some(Format::Srt)
+    } else {
+        None
+    }
+}
+
+/// Guesses the input format from its extension, falling back to sniffing the
+/// `WEBVTT` header for extensionless input such as stdin.
+fn detect_input_format(name: &str, text: &str) -> Format {
+    format_from_extension(name).unwrap_or_else(|| {
+        if text.trim_start().starts_with("WEBVTT") {
+            Format::Vtt
+        } else {
+            Format::Srt
+        }
+    })
 }
 
 fn main() {
     let args = Arguments::parse();
 
-    let adjustment = match parse_time(&args.adjustment.replace(&['+', '-'], ""), 0) {
+    if !args.anchors.is_empty() && args.anchors.len() != 2 {
+        eprintln!("--anchor must be given exactly twice");
+        process::exit(1);
+    }
+
+    // A single positional that looks like a time rather than a path is the
+    // adjustment, with the file implicitly read from stdin, so that
+    // `cat a.srt | chousei 00:00:05` works without spelling out the `-`.
+    let (file, adjustment_arg) = match (&args.file, &args.adjustment) {
+        (Some(value), None) if value != "-" && looks_like_time(value) => {
+            (None, Some(value.clone()))
+        }
+        _ => (args.file.clone(), args.adjustment.clone()),
+    };
+
+    let adjustment_text = adjustment_arg.unwrap_or_else(|| "0".into());
+    let adjustment = match parse_cli_time(&adjustment_text.replace(&['+', '-'], "")) {
         Ok(adjustment) => adjustment,
-        Err(err) => {
-            eprintln!("{}", err.message);
+        Err(message) => {
+            eprintln!("{}", message);
             process::exit(1);
         }
     };
-    let neg = args.adjustment.starts_with('-');
+    let neg = adjustment_text.starts_with('-');
+
+    let pivot = args
+        .pivot
+        .as_deref()
+        .map(|text| match parse_cli_time(text) {
+            Ok(pivot) => pivot,
+            Err(message) => {
+                eprintln!("{}", message);
+                process::exit(1);
+            }
+        });
+
+    let after = args
+        .after
+        .as_deref()
+        .map(|text| match parse_cli_time(text) {
+            Ok(after) => after,
+            Err(message) => {
+                eprintln!("{}", message);
+                process::exit(1);
+            }
+        });
 
-    let path = Path::new(&args.file);
-    let text = match fs::read_to_string(path) {
-        Ok(text) => text,
-        Err(_) => {
-            eprintln!("Failed to read the input file {}", path.display());
+    let input_is_stdin = file.as_deref().map_or(true, |file| file == "-");
+    let display_name = if input_is_stdin {
+        "<stdin>".to_string()
+    } else {
+        file.unwrap()
+    };
+
+    let text = if input_is_stdin {
+        let mut text = String::new();
+        if io::stdin().read_to_string(&mut text).is_err() {
+            eprintln!("Failed to read from stdin");
             process::exit(1);
         }
+        text
+    } else {
+        match fs::read_to_string(&display_name) {
+            Ok(text) => text,
+            Err(_) => {
+                eprintln!("Failed to read the input file {}", display_name);
+                process::exit(1);
+            }
+        }
     };
     let text = &text.replace(&['\r', '\u{feff}'], "");
 
-    let mut subtitles = parse_srt(&text).unwrap_or_else(|error| {
-        let file = SimpleFile::new(path.file_name().unwrap().to_str().unwrap(), text);
+    // `--format` requests an *output* format, e.g. to convert SRT to VTT while
+    // piping through stdin/stdout, where there's no output extension to sniff;
+    // `--input-format` is separate so extensionless/header-less input can still
+    // be forced to parse as a specific format.
+    let input_format = match args.input_format {
+        Format::Auto => detect_input_format(&display_name, text),
+        format => format,
+    };
+
+    let mut subtitles = match input_format {
+        Format::Vtt => Vtt::parse(text),
+        _ => Srt::parse(text),
+    }
+    .unwrap_or_else(|error| {
+        let file = SimpleFile::new(&display_name, text);
 
         let diagnostic = Diagnostic::error()
             .with_message(error.message)
@@ -62,22 +219,88 @@ fn main() {
         process::exit(1);
     });
 
-    for subtitle in subtitles.iter_mut() {
-        if neg {
-            subtitle.from -= adjustment;
-            subtitle.to -= adjustment;
-        } else {
-            subtitle.from += adjustment;
-            subtitle.to += adjustment;
+    let in_range = |subtitle: &Subtitle| {
+        if let Some(from) = args.from {
+            if subtitle.number < from {
+                return false;
+            }
+        }
+        if let Some(to) = args.to {
+            if subtitle.number > to {
+                return false;
+            }
+        }
+        if let Some(after) = after {
+            if subtitle.from < after {
+                return false;
+            }
+        }
+        true
+    };
+
+    if args.anchors.is_empty() {
+        let scale = args.scale.unwrap_or(1.0);
+        let pivot = pivot.unwrap_or_else(|| subtitles.first().map_or(0, |subtitle| subtitle.from));
+
+        for subtitle in subtitles.iter_mut() {
+            if !in_range(subtitle) {
+                continue;
+            }
+
+            subtitle.from = scale_time(subtitle.from, pivot, scale);
+            subtitle.to = scale_time(subtitle.to, pivot, scale);
+
+            if neg {
+                subtitle.from = subtitle.from.saturating_sub(adjustment);
+                subtitle.to = subtitle.to.saturating_sub(adjustment);
+            } else {
+                subtitle.from += adjustment;
+                subtitle.to += adjustment;
+            }
+        }
+    } else {
+        let (scale, offset) = solve_anchors(&args.anchors, &subtitles).unwrap_or_else(|message| {
+            eprintln!("{}", message);
+            process::exit(1);
+        });
+
+        for subtitle in subtitles.iter_mut() {
+            if !in_range(subtitle) {
+                continue;
+            }
+
+            subtitle.from = affine_time(subtitle.from, scale, offset);
+            subtitle.to = affine_time(subtitle.to, scale, offset);
         }
     }
 
-    let output = print_subtitles(&subtitles);
+    let output_format = match args.format {
+        Format::Auto => format_from_extension(args.output.as_deref().unwrap_or(&display_name))
+            .unwrap_or(input_format),
+        format => format,
+    };
+
+    let output = match output_format {
+        Format::Vtt => Vtt::print(&subtitles),
+        _ => Srt::print(&subtitles),
+    };
 
-    let write_result = fs::write(args.output.unwrap_or(args.file.clone()), output);
-    if write_result.is_err() {
-        eprintln!("Failed to write the output file {}", path.display());
-        process::exit(1);
+    let output_is_stdout = match args.output.as_deref() {
+        Some(output) => output == "-",
+        None => input_is_stdin,
+    };
+
+    if output_is_stdout {
+        if io::stdout().write_all(output.as_bytes()).is_err() {
+            eprintln!("Failed to write to stdout");
+            process::exit(1);
+        }
+    } else {
+        let output_path = args.output.unwrap_or(display_name);
+        if fs::write(&output_path, output).is_err() {
+            eprintln!("Failed to write the output file {}", output_path);
+            process::exit(1);
+        }
     }
 }
 
@@ -86,8 +309,11 @@ struct Subtitle<'a> {
     from: u32, // millis
     to: u32,   // millis
     lines: Vec<&'a str>,
+    cue_id: Option<&'a str>,       // WebVTT cue identifier, if any
+    cue_settings: Option<&'a str>, // WebVTT cue settings, e.g. "align:start position:10%"
 }
 
+#[derive(Debug)]
 struct ParseError {
     message: String,
     reason: String,
@@ -164,6 +390,8 @@ fn parse_srt(text: &str) -> Result<Vec<Subtitle>, ParseError> {
             from,
             to,
             lines,
+            cue_id: None,
+            cue_settings: None,
         })
     }
 
@@ -171,6 +399,12 @@ fn parse_srt(text: &str) -> Result<Vec<Subtitle>, ParseError> {
 }
 
 fn parse_time(text: &str, index: usize) -> Result<u32, ParseError> {
+    parse_time_with_separator(text, ',', index)
+}
+
+/// A more lenient timestamp parser for command-line arguments, accepting `,` or
+/// `.` before the fractional seconds and omitted hours/minutes.
+fn parse_cli_time(text: &str) -> Result<u32, String> {
     let mut number_strs: Vec<&str> = text.splitn(3, ':').collect();
     number_strs.reverse();
     let mut number_strs_iter = number_strs.iter();
@@ -178,7 +412,59 @@ fn parse_time(text: &str, index: usize) -> Result<u32, ParseError> {
     let mut seconds = 0;
     let mut millis = 0;
     if let Some(seconds_str) = number_strs_iter.next() {
-        let (seconds_str, millis_str) = seconds_str.split_once(',').unwrap_or((seconds_str, "0"));
+        let (seconds_str, millis_str) = seconds_str
+            .split_once(|c| c == ',' || c == '.')
+            .unwrap_or((seconds_str, "0"));
+        seconds = seconds_str
+            .parse::<u32>()
+            .map_err(|_| format!("Failed to parse {:?} as an integer", seconds_str))?;
+        let millis_chars: Vec<char> = millis_str.chars().collect();
+        millis = match millis_chars.len() {
+            0 => Ok(0),
+            1 => millis_str.parse::<u32>().map(|millis| millis * 100),
+            2 => millis_str.parse::<u32>().map(|millis| millis * 10),
+            _ => millis_chars[..3].iter().collect::<String>().parse::<u32>(),
+        }
+        .map_err(|_| format!("Failed to parse {:?} as milliseconds", millis_str))?;
+    }
+
+    let mut minutes = 0;
+    if let Some(minutes_str) = number_strs_iter.next() {
+        minutes = minutes_str
+            .parse::<u32>()
+            .map_err(|_| format!("Failed to parse {:?} as an integer", minutes_str))?;
+    }
+
+    let mut hours = 0;
+    if let Some(hours_str) = number_strs_iter.next() {
+        hours = hours_str
+            .parse::<u32>()
+            .map_err(|_| format!("Failed to parse {:?} as an integer", hours_str))?;
+    }
+
+    Ok(hours * HOUR + minutes * MINUTE + seconds * SECOND + millis)
+}
+
+fn looks_like_time(text: &str) -> bool {
+    parse_cli_time(&text.replace(&['+', '-'], "")).is_ok()
+}
+
+/// Parses a WebVTT `HH:MM:SS.mmm` timestamp, which uses a dot instead of a comma.
+fn parse_vtt_time(text: &str, index: usize) -> Result<u32, ParseError> {
+    parse_time_with_separator(text, '.', index)
+}
+
+fn parse_time_with_separator(text: &str, separator: char, index: usize) -> Result<u32, ParseError> {
+    let mut number_strs: Vec<&str> = text.splitn(3, ':').collect();
+    number_strs.reverse();
+    let mut number_strs_iter = number_strs.iter();
+
+    let mut seconds = 0;
+    let mut millis = 0;
+    if let Some(seconds_str) = number_strs_iter.next() {
+        let (seconds_str, millis_str) = seconds_str
+            .split_once(separator)
+            .unwrap_or((seconds_str, "0"));
         seconds = match seconds_str.parse::<u32>() {
             Ok(seconds) => seconds,
             Err(_) => {
@@ -244,6 +530,52 @@ fn parse_time(text: &str, index: usize) -> Result<u32, ParseError> {
     Ok(hours * HOUR + minutes * MINUTE + seconds * SECOND + millis)
 }
 
+fn solve_anchors(anchors: &[String], subtitles: &[Subtitle]) -> Result<(f64, f64), String> {
+    let mut points = Vec::with_capacity(2);
+    for anchor in anchors {
+        let (origin_text, time_text) = anchor.split_once('=').ok_or_else(|| {
+            format!(
+                "Invalid --anchor {:?}, expected NUMBER=TIME or TIME=TIME",
+                anchor
+            )
+        })?;
+        let target = parse_cli_time(time_text)?;
+        // An anchor's original position is selected either by subtitle number
+        // (looked up against the input) or, failing that, by its current time
+        // directly (which need not match any subtitle's start time exactly).
+        let origin = match origin_text.parse::<u32>() {
+            Ok(number) => {
+                subtitles
+                    .iter()
+                    .find(|subtitle| subtitle.number == number)
+                    .ok_or_else(|| format!("No subtitle numbered {} in the input", number))?
+                    .from as f64
+            }
+            Err(_) => parse_cli_time(origin_text)? as f64,
+        };
+        points.push((origin, target as f64));
+    }
+
+    let (o_a, t_a) = points[0];
+    let (o_b, t_b) = points[1];
+    if o_a == o_b {
+        return Err("The two --anchor subtitles have the same original time".into());
+    }
+
+    let scale = (t_b - t_a) / (o_b - o_a);
+    let offset = t_a - scale * o_a;
+    Ok((scale, offset))
+}
+
+fn affine_time(value: u32, scale: f64, offset: f64) -> u32 {
+    (scale * value as f64 + offset).round().max(0.0) as u32
+}
+
+fn scale_time(value: u32, pivot: u32, ratio: f64) -> u32 {
+    let scaled = pivot as f64 + (value as f64 - pivot as f64) * ratio;
+    scaled.round().max(0.0) as u32
+}
+
 fn print_subtitles(subtitles: &[Subtitle]) -> String {
     let mut text = String::new();
     for subtitle in subtitles.iter() {
@@ -280,3 +612,265 @@ fn print_time(millis: u32) -> String {
         hours, minutes, seconds, leftover
     )
 }
+
+fn parse_vtt(text: &str) -> Result<Vec<Subtitle>, ParseError> {
+    let mut subtitles: Vec<Subtitle> = vec![];
+    let mut lines_iter = text.lines();
+    let mut index = 0usize;
+    let mut number = 0u32;
+
+    if let Some(header_line) = text.lines().next() {
+        if header_line.trim_start().starts_with("WEBVTT") {
+            lines_iter.next();
+            index += header_line.width() + 1;
+        }
+    }
+
+    while let Some(mut line) = lines_iter.next() {
+        if line.is_empty() {
+            index += 1;
+            continue;
+        }
+
+        // A cue identifier is optional; skip past it to find the timestamp line,
+        // keeping it around so it can be written back out unchanged.
+        let mut cue_id = None;
+        if !line.contains(ARROW_SEPARATOR) {
+            cue_id = Some(line);
+            index += line.width() + 1;
+            line = match lines_iter.next() {
+                Some(line) => line,
+                None => {
+                    return Err(ParseError {
+                        message: format!("Expected to find time line for cue {:?}", line),
+                        reason: "Missing time line".into(),
+                        range: Range {
+                            start: index,
+                            end: index,
+                        },
+                    })
+                }
+            };
+        }
+        let time_line = line;
+        number += 1;
+
+        let (from_text, rest) = match time_line.split_once(ARROW_SEPARATOR) {
+            Some(parts) => parts,
+            None => {
+                return Err(ParseError {
+                    message: format!("Expected to find arrow in time line for cue {}", number),
+                    reason: format!("Missing '{}'", ARROW_SEPARATOR),
+                    range: Range {
+                        start: index,
+                        end: index + time_line.width(),
+                    },
+                })
+            }
+        };
+        // Cue settings may follow the `to` timestamp, separated by whitespace.
+        let mut rest_parts = rest.splitn(2, char::is_whitespace);
+        let to_text = rest_parts.next().unwrap_or(rest);
+        let cue_settings = rest_parts
+            .next()
+            .map(str::trim_start)
+            .filter(|s| !s.is_empty());
+
+        let from = parse_vtt_time(from_text, index)?;
+        let to = parse_vtt_time(to_text, index + from_text.width() + ARROW_SEPARATOR.width())?;
+
+        index += time_line.len() + 1;
+
+        let mut lines: Vec<&str> = vec![];
+        while let Some(line) = lines_iter.next() {
+            index += line.width() + 1;
+            if line.is_empty() {
+                break;
+            }
+            lines.push(line);
+        }
+
+        subtitles.push(Subtitle {
+            number,
+            from,
+            to,
+            lines,
+            cue_id,
+            cue_settings,
+        })
+    }
+
+    Ok(subtitles)
+}
+
+fn print_vtt(subtitles: &[Subtitle]) -> String {
+    let mut text = String::from("WEBVTT\n");
+    for subtitle in subtitles.iter() {
+        let string = print_vtt_cue(subtitle);
+        text.push('\n');
+        text.push_str(&string);
+    }
+    text
+}
+
+fn print_vtt_cue(subtitle: &Subtitle) -> String {
+    let mut text = String::new();
+    if let Some(cue_id) = subtitle.cue_id {
+        text.push_str(&format!("{}\n", cue_id));
+    }
+    text.push_str(&format!(
+        "{} --> {}",
+        print_vtt_time(subtitle.from),
+        print_vtt_time(subtitle.to)
+    ));
+    if let Some(cue_settings) = subtitle.cue_settings {
+        text.push_str(&format!(" {}", cue_settings));
+    }
+    text.push('\n');
+    for line in subtitle.lines.iter() {
+        text.push_str(&format!("{}\n", line));
+    }
+    text
+}
+
+fn print_vtt_time(millis: u32) -> String {
+    let hours = millis / HOUR;
+    let mut leftover = millis - hours * HOUR;
+    let minutes = leftover / MINUTE;
+    leftover -= minutes * MINUTE;
+    let seconds = leftover / SECOND;
+    leftover -= seconds * SECOND;
+    format!(
+        "{:0>2}:{:0>2}:{:0>2}.{:0>3}",
+        hours, minutes, seconds, leftover
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_time_stretches_around_the_pivot() {
+        assert_eq!(scale_time(2000, 1000, 2.0), 3000);
+        assert_eq!(scale_time(0, 1000, 2.0), 0);
+    }
+
+    #[test]
+    fn affine_time_applies_scale_and_offset() {
+        assert_eq!(affine_time(1000, 2.0, 500.0), 2500);
+        assert_eq!(affine_time(0, 1.0, -500.0), 0);
+    }
+
+    #[test]
+    fn parse_cli_time_accepts_lenient_formats() {
+        assert_eq!(parse_cli_time("00:00:05").unwrap(), 5000);
+        assert_eq!(parse_cli_time("00:00:05.5").unwrap(), 5500);
+        assert_eq!(parse_cli_time("00:00:05,75").unwrap(), 5750);
+        assert_eq!(parse_cli_time("5").unwrap(), 5000);
+        assert_eq!(parse_cli_time("14.52").unwrap(), 14520);
+    }
+
+    #[test]
+    fn parse_cli_time_rejects_non_char_boundary_millis_without_panicking() {
+        assert!(parse_cli_time("00:00:05.1€").is_err());
+    }
+
+    #[test]
+    fn solve_anchors_derives_scale_and_offset() {
+        let subtitles = vec![
+            Subtitle {
+                number: 1,
+                from: 1000,
+                to: 2000,
+                lines: vec![],
+                cue_id: None,
+                cue_settings: None,
+            },
+            Subtitle {
+                number: 2,
+                from: 2000,
+                to: 3000,
+                lines: vec![],
+                cue_id: None,
+                cue_settings: None,
+            },
+        ];
+        let anchors = vec!["1=00:00:01,500".to_string(), "2=00:00:03,500".to_string()];
+        let (scale, offset) = solve_anchors(&anchors, &subtitles).unwrap();
+        assert_eq!(affine_time(1000, scale, offset), 1500);
+        assert_eq!(affine_time(2000, scale, offset), 3500);
+    }
+
+    #[test]
+    fn solve_anchors_accepts_current_time_in_place_of_subtitle_number() {
+        let subtitles = vec![
+            Subtitle {
+                number: 1,
+                from: 1000,
+                to: 2000,
+                lines: vec![],
+                cue_id: None,
+                cue_settings: None,
+            },
+            Subtitle {
+                number: 2,
+                from: 2000,
+                to: 3000,
+                lines: vec![],
+                cue_id: None,
+                cue_settings: None,
+            },
+        ];
+        let anchors = vec![
+            "00:00:01,000=00:00:01,500".to_string(),
+            "00:00:02,000=00:00:03,500".to_string(),
+        ];
+        let (scale, offset) = solve_anchors(&anchors, &subtitles).unwrap();
+        assert_eq!(affine_time(1000, scale, offset), 1500);
+        assert_eq!(affine_time(2000, scale, offset), 3500);
+    }
+
+    #[test]
+    fn solve_anchors_rejects_identical_original_times() {
+        let subtitles = vec![
+            Subtitle {
+                number: 1,
+                from: 1000,
+                to: 2000,
+                lines: vec![],
+                cue_id: None,
+                cue_settings: None,
+            },
+            Subtitle {
+                number: 2,
+                from: 1000,
+                to: 2000,
+                lines: vec![],
+                cue_id: None,
+                cue_settings: None,
+            },
+        ];
+        let anchors = vec!["1=00:00:01,000".to_string(), "2=00:00:02,000".to_string()];
+        assert!(solve_anchors(&anchors, &subtitles).is_err());
+    }
+
+    #[test]
+    fn parse_vtt_keeps_cue_identifiers_and_settings() {
+        let text =
+            "WEBVTT\n\ncue-1\n00:00:01.000 --> 00:00:02.000 align:start position:10%\nHello\n";
+        let subtitles = parse_vtt(text).unwrap();
+        assert_eq!(subtitles[0].cue_id, Some("cue-1"));
+        assert_eq!(subtitles[0].cue_settings, Some("align:start position:10%"));
+        assert_eq!(print_vtt(&subtitles), text);
+    }
+
+    #[test]
+    fn parse_vtt_without_cue_identifier_or_settings() {
+        let text = "WEBVTT\n\n00:00:01.000 --> 00:00:02.000\nHello\n";
+        let subtitles = parse_vtt(text).unwrap();
+        assert_eq!(subtitles[0].cue_id, None);
+        assert_eq!(subtitles[0].cue_settings, None);
+        assert_eq!(print_vtt(&subtitles), text);
+    }
+}